@@ -1,3 +1,12 @@
+//! An intermediate language for intermediate languages.
+//!
+//! The in-memory model only requires `alloc`; the `std` feature (enabled by default) provides everything that depends on
+//! `std::io` and `std::error::Error`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 #[cfg(test)]
 mod tests {
     #[test]